@@ -1,12 +1,12 @@
 use clap::Parser;
 use rayon::prelude::*;
-use sha2::{Digest, Sha256};
+use sha2::Digest;
 use std::io::Write;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::thread;
 use std::time::{Duration, Instant};
 
-/// Find fixed-point hash strings for SHA-256
+/// Find fixed-point hash strings for common hash algorithms
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
@@ -14,13 +14,29 @@ struct Args {
     #[arg(short, long, default_value_t = 7)]
     digits: u8,
 
-    /// Text template with # as placeholder for the hash
-    #[arg(
-        short,
-        long,
-        default_value = "The SHA-256 hash of this sentence begins with #."
-    )]
-    text: String,
+    /// Text template with # as placeholder for the hash. Defaults to a
+    /// template naming the selected --algorithm.
+    #[arg(short, long)]
+    text: Option<String>,
+
+    /// Hash algorithm to search with
+    #[arg(short, long, value_enum, default_value_t = Algorithm::Sha256)]
+    algorithm: Algorithm,
+
+    /// Proof-of-work mode: require this many leading zero bits in the full
+    /// digest instead of matching --digits hex characters
+    #[arg(long, conflicts_with = "nbits", conflicts_with = "pattern")]
+    zero_bits: Option<u32>,
+
+    /// Proof-of-work mode: Bitcoin-style compact difficulty target, as hex
+    /// (e.g. 1d00ffff), instead of matching --digits hex characters
+    #[arg(long, value_parser = parse_nbits, conflicts_with = "pattern")]
+    nbits: Option<u32>,
+
+    /// Vanity mode: accept a candidate whose full lowercase-hex digest
+    /// matches this regex, instead of matching --digits hex characters
+    #[arg(long)]
+    pattern: Option<String>,
 
     /// Quiet mode: only print the result string
     #[arg(short, long)]
@@ -31,6 +47,120 @@ struct Args {
     verbose: bool,
 }
 
+/// Hash algorithms `solve` knows how to search with. Each variant maps to a
+/// [`HashAlgo`] implementation via [`make_hash_algo`].
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Algorithm {
+    Sha256,
+    Sha512,
+    Sha1,
+    #[value(name = "sha3-256")]
+    Sha3_256,
+    Md5,
+    Blake3,
+}
+
+impl std::fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Algorithm::Sha256 => "sha256",
+            Algorithm::Sha512 => "sha512",
+            Algorithm::Sha1 => "sha1",
+            Algorithm::Sha3_256 => "sha3-256",
+            Algorithm::Md5 => "md5",
+            Algorithm::Blake3 => "blake3",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Algorithm {
+    /// Digest length in bytes, used to bound `--digits` and size buffers.
+    fn output_len(self) -> usize {
+        match self {
+            Algorithm::Sha256 => 32,
+            Algorithm::Sha512 => 64,
+            Algorithm::Sha1 => 20,
+            Algorithm::Sha3_256 => 32,
+            Algorithm::Md5 => 16,
+            Algorithm::Blake3 => 32,
+        }
+    }
+
+    /// Conventional capitalized name, used in the default `--text` template
+    /// so it actually names the hash the search is run against.
+    fn display_name(self) -> &'static str {
+        match self {
+            Algorithm::Sha256 => "SHA-256",
+            Algorithm::Sha512 => "SHA-512",
+            Algorithm::Sha1 => "SHA-1",
+            Algorithm::Sha3_256 => "SHA3-256",
+            Algorithm::Md5 => "MD5",
+            Algorithm::Blake3 => "BLAKE3",
+        }
+    }
+}
+
+/// The largest digest length among the supported algorithms (SHA-512),
+/// used to size a fixed output buffer so the digest output itself needs no
+/// allocation.
+const MAX_DIGEST_LEN: usize = 64;
+
+/// The largest `--digits` value for which `16u64.pow(digits)` (the search
+/// space size `solve` computes `max_count` from) still fits in a `u64`;
+/// `16u64.pow(16)` is `2^64`, one past `u64::MAX`, so 15 is the true limit.
+const MAX_SAFE_DIGITS: u8 = 15;
+
+/// A hash algorithm solve() can drive generically: reset/update/finalize
+/// over a fixed-size output buffer, so callers never need to know which
+/// concrete digest type is behind the trait object.
+trait HashAlgo: Send {
+    fn update(&mut self, data: &[u8]);
+    fn finalize_into_reset(&mut self, out: &mut [u8; MAX_DIGEST_LEN]);
+}
+
+/// Adapts any `digest::Digest` implementation (sha2, sha1, sha3, md-5) to
+/// `HashAlgo`.
+struct DigestAlgo<D>(D);
+
+impl<D: Digest + sha2::digest::FixedOutputReset + Send> HashAlgo for DigestAlgo<D> {
+    fn update(&mut self, data: &[u8]) {
+        Digest::update(&mut self.0, data);
+    }
+
+    fn finalize_into_reset(&mut self, out: &mut [u8; MAX_DIGEST_LEN]) {
+        let len = <D as Digest>::output_size();
+        let digest = Digest::finalize_reset(&mut self.0);
+        out[..len].copy_from_slice(&digest);
+    }
+}
+
+/// BLAKE3 doesn't implement `digest::Digest`, so it gets its own thin
+/// adapter around `blake3::Hasher`.
+struct Blake3Algo(blake3::Hasher);
+
+impl HashAlgo for Blake3Algo {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize_into_reset(&mut self, out: &mut [u8; MAX_DIGEST_LEN]) {
+        out[..32].copy_from_slice(self.0.finalize().as_bytes());
+        self.0.reset();
+    }
+}
+
+fn make_hash_algo(algorithm: Algorithm) -> Box<dyn HashAlgo> {
+    match algorithm {
+        Algorithm::Sha256 => Box::new(DigestAlgo(sha2::Sha256::new())),
+        Algorithm::Sha512 => Box::new(DigestAlgo(sha2::Sha512::new())),
+        Algorithm::Sha1 => Box::new(DigestAlgo(sha1::Sha1::new())),
+        Algorithm::Sha3_256 => Box::new(DigestAlgo(sha3::Sha3_256::new())),
+        Algorithm::Md5 => Box::new(DigestAlgo(md5::Md5::new())),
+        Algorithm::Blake3 => Box::new(Blake3Algo(blake3::Hasher::new())),
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum VerbosityLevel {
     Quiet,
@@ -41,6 +171,430 @@ enum VerbosityLevel {
 static FOUND: AtomicBool = AtomicBool::new(false);
 static OPS_COUNT: AtomicU64 = AtomicU64::new(0);
 
+// A small standalone SHA-256 implementation. The `sha2` crate doesn't expose
+// its intermediate compression state, but `solve` needs to cache the state
+// after the constant leading blocks of the template so they aren't rehashed for
+// every candidate.
+mod sha256 {
+    pub const INIT_STATE: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    pub(crate) const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4,
+        0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe,
+        0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f,
+        0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7,
+        0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc,
+        0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
+        0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116,
+        0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+
+    /// The eight-word internal state after absorbing some whole number of
+    /// 64-byte blocks.
+    #[derive(Clone, Copy)]
+    pub struct Midstate {
+        pub state: [u32; 8],
+    }
+
+    pub(crate) fn compress_block(state: &mut [u32; 8], block: &[u8; 64]) {
+        let mut w = [0u32; 64];
+        for i in 0..16 {
+            w[i] = u32::from_be_bytes([
+                block[i * 4],
+                block[i * 4 + 1],
+                block[i * 4 + 2],
+                block[i * 4 + 3],
+            ]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ ((!e) & g);
+            let temp1 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+        state[4] = state[4].wrapping_add(e);
+        state[5] = state[5].wrapping_add(f);
+        state[6] = state[6].wrapping_add(g);
+        state[7] = state[7].wrapping_add(h);
+    }
+
+    /// Absorb zero or more complete 64-byte blocks and return the resulting
+    /// midstate. `blocks.len()` must be a multiple of 64.
+    pub fn compute_midstate(blocks: &[u8]) -> Midstate {
+        debug_assert_eq!(blocks.len() % 64, 0);
+        let mut state = INIT_STATE;
+        for chunk in blocks.chunks_exact(64) {
+            compress_block(&mut state, chunk.try_into().unwrap());
+        }
+        Midstate { state }
+    }
+
+    /// Pad `tail` into a whole number of 64-byte blocks, given that
+    /// `consumed` bytes of message precede it. Shared by the scalar and
+    /// SIMD batch paths so they pad identically.
+    pub(crate) fn pad_message(consumed: usize, tail: &[u8]) -> Vec<u8> {
+        let total_len_bits = ((consumed + tail.len()) as u64) * 8;
+
+        let mut buf = Vec::with_capacity(tail.len() + 72);
+        buf.extend_from_slice(tail);
+        buf.push(0x80);
+        while buf.len() % 64 != 56 {
+            buf.push(0);
+        }
+        buf.extend_from_slice(&total_len_bits.to_be_bytes());
+        buf
+    }
+
+    /// Finish a digest from a cached midstate. `consumed` is the number of
+    /// message bytes already folded into `midstate`; `tail` is the remainder
+    /// of the message (everything after those blocks).
+    pub fn finalize_from_midstate(midstate: &Midstate, consumed: usize, tail: &[u8]) -> [u8; 32] {
+        let mut state = midstate.state;
+        let buf = pad_message(consumed, tail);
+
+        for chunk in buf.chunks_exact(64) {
+            compress_block(&mut state, chunk.try_into().unwrap());
+        }
+
+        let mut out = [0u8; 32];
+        for (i, word) in state.iter().enumerate() {
+            out[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+        }
+        out
+    }
+}
+
+// SIMD batch hashing: packs several candidates into parallel SHA-256 lanes
+// so one call to the compression function advances all of them at once,
+// instead of hashing one candidate at a time. Runtime CPU feature
+// detection picks AVX-512 (16 lanes), AVX2 (8 lanes), or the scalar
+// `sha256` module above as a fallback.
+#[cfg(target_arch = "x86_64")]
+mod simd_sha256 {
+    use super::sha256;
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum Level {
+        Avx512,
+        Avx2,
+        Scalar,
+    }
+
+    impl Level {
+        pub fn detect() -> Level {
+            if is_x86_feature_detected!("avx512f") {
+                Level::Avx512
+            } else if is_x86_feature_detected!("avx2") {
+                Level::Avx2
+            } else {
+                Level::Scalar
+            }
+        }
+
+        pub fn lanes(self) -> usize {
+            match self {
+                Level::Avx512 => 16,
+                Level::Avx2 => 8,
+                Level::Scalar => 1,
+            }
+        }
+    }
+
+    /// Hash `lanes` independently-padded messages sharing the same base
+    /// state (e.g. a cached midstate), `lanes` matching `level.lanes()`.
+    /// All `tails` must already be padded to the same length (true for
+    /// this tool, since every candidate's tail differs only in the
+    /// placeholder digits, never in length).
+    pub fn hash_batch(level: Level, base_state: [u32; 8], tails: &[Vec<u8>]) -> Vec<[u8; 32]> {
+        debug_assert_eq!(tails.len(), level.lanes());
+        match level {
+            Level::Avx512 => unsafe { avx512::hash_batch16(base_state, tails) },
+            Level::Avx2 => unsafe { avx2::hash_batch8(base_state, tails) },
+            Level::Scalar => tails
+                .iter()
+                .map(|tail| {
+                    let mut state = base_state;
+                    for chunk in tail.chunks_exact(64) {
+                        sha256::compress_block(&mut state, chunk.try_into().unwrap());
+                    }
+                    let mut out = [0u8; 32];
+                    for (i, word) in state.iter().enumerate() {
+                        out[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+                    }
+                    out
+                })
+                .collect(),
+        }
+    }
+
+    mod avx2 {
+        use super::super::sha256::K;
+        use std::arch::x86_64::*;
+
+        macro_rules! ror {
+            ($x:expr, $n:literal) => {
+                _mm256_or_si256(_mm256_srli_epi32($x, $n), _mm256_slli_epi32($x, 32 - $n))
+            };
+        }
+
+        #[target_feature(enable = "avx2")]
+        unsafe fn expand_message(w: &mut [__m256i; 64]) {
+            for i in 16..64 {
+                let s0 = _mm256_xor_si256(
+                    _mm256_xor_si256(ror!(w[i - 15], 7), ror!(w[i - 15], 18)),
+                    _mm256_srli_epi32(w[i - 15], 3),
+                );
+                let s1 = _mm256_xor_si256(
+                    _mm256_xor_si256(ror!(w[i - 2], 17), ror!(w[i - 2], 19)),
+                    _mm256_srli_epi32(w[i - 2], 10),
+                );
+                w[i] = _mm256_add_epi32(
+                    _mm256_add_epi32(w[i - 16], s0),
+                    _mm256_add_epi32(w[i - 7], s1),
+                );
+            }
+        }
+
+        #[target_feature(enable = "avx2")]
+        unsafe fn compress(state: &mut [__m256i; 8], w: &[__m256i; 64], k: &[__m256i; 64]) {
+            let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+            for i in 0..64 {
+                let s1 = _mm256_xor_si256(_mm256_xor_si256(ror!(e, 6), ror!(e, 11)), ror!(e, 25));
+                let ch = _mm256_xor_si256(_mm256_and_si256(e, f), _mm256_andnot_si256(e, g));
+                let temp1 = _mm256_add_epi32(
+                    _mm256_add_epi32(_mm256_add_epi32(h, s1), ch),
+                    _mm256_add_epi32(k[i], w[i]),
+                );
+                let s0 = _mm256_xor_si256(_mm256_xor_si256(ror!(a, 2), ror!(a, 13)), ror!(a, 22));
+                let maj = _mm256_xor_si256(
+                    _mm256_xor_si256(_mm256_and_si256(a, b), _mm256_and_si256(a, c)),
+                    _mm256_and_si256(b, c),
+                );
+                let temp2 = _mm256_add_epi32(s0, maj);
+
+                h = g;
+                g = f;
+                f = e;
+                e = _mm256_add_epi32(d, temp1);
+                d = c;
+                c = b;
+                b = a;
+                a = _mm256_add_epi32(temp1, temp2);
+            }
+
+            state[0] = _mm256_add_epi32(state[0], a);
+            state[1] = _mm256_add_epi32(state[1], b);
+            state[2] = _mm256_add_epi32(state[2], c);
+            state[3] = _mm256_add_epi32(state[3], d);
+            state[4] = _mm256_add_epi32(state[4], e);
+            state[5] = _mm256_add_epi32(state[5], f);
+            state[6] = _mm256_add_epi32(state[6], g);
+            state[7] = _mm256_add_epi32(state[7], h);
+        }
+
+        #[target_feature(enable = "avx2")]
+        pub(super) unsafe fn hash_batch8(base_state: [u32; 8], tails: &[Vec<u8>]) -> Vec<[u8; 32]> {
+            const LANES: usize = 8;
+            debug_assert_eq!(tails.len(), LANES);
+
+            let mut state: [__m256i; 8] =
+                std::array::from_fn(|i| _mm256_set1_epi32(base_state[i] as i32));
+            let k: [__m256i; 64] = std::array::from_fn(|i| _mm256_set1_epi32(K[i] as i32));
+
+            let block_count = tails[0].len() / 64;
+            for block_idx in 0..block_count {
+                let mut w = [_mm256_setzero_si256(); 64];
+                for (word_idx, w_slot) in w.iter_mut().enumerate().take(16) {
+                    let mut lane = [0i32; LANES];
+                    for (l, tail) in tails.iter().enumerate() {
+                        let off = block_idx * 64 + word_idx * 4;
+                        lane[l] = u32::from_be_bytes(tail[off..off + 4].try_into().unwrap()) as i32;
+                    }
+                    *w_slot = _mm256_set_epi32(
+                        lane[7], lane[6], lane[5], lane[4], lane[3], lane[2], lane[1], lane[0],
+                    );
+                }
+                expand_message(&mut w);
+                compress(&mut state, &w, &k);
+            }
+
+            let mut lane_words = [[0i32; LANES]; 8];
+            for (word_idx, lane_out) in lane_words.iter_mut().enumerate() {
+                _mm256_storeu_si256(lane_out.as_mut_ptr() as *mut __m256i, state[word_idx]);
+            }
+
+            (0..LANES)
+                .map(|l| {
+                    let mut out = [0u8; 32];
+                    for (word_idx, lane_out) in lane_words.iter().enumerate() {
+                        out[word_idx * 4..word_idx * 4 + 4]
+                            .copy_from_slice(&(lane_out[l] as u32).to_be_bytes());
+                    }
+                    out
+                })
+                .collect()
+        }
+    }
+
+    mod avx512 {
+        use super::super::sha256::K;
+        use std::arch::x86_64::*;
+
+        #[target_feature(enable = "avx512f")]
+        unsafe fn expand_message(w: &mut [__m512i; 64]) {
+            for i in 16..64 {
+                let s0 = _mm512_xor_si512(
+                    _mm512_xor_si512(
+                        _mm512_ror_epi32(w[i - 15], 7),
+                        _mm512_ror_epi32(w[i - 15], 18),
+                    ),
+                    _mm512_srli_epi32(w[i - 15], 3),
+                );
+                let s1 = _mm512_xor_si512(
+                    _mm512_xor_si512(
+                        _mm512_ror_epi32(w[i - 2], 17),
+                        _mm512_ror_epi32(w[i - 2], 19),
+                    ),
+                    _mm512_srli_epi32(w[i - 2], 10),
+                );
+                w[i] = _mm512_add_epi32(
+                    _mm512_add_epi32(w[i - 16], s0),
+                    _mm512_add_epi32(w[i - 7], s1),
+                );
+            }
+        }
+
+        #[target_feature(enable = "avx512f")]
+        unsafe fn compress(state: &mut [__m512i; 8], w: &[__m512i; 64], k: &[__m512i; 64]) {
+            let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+            for i in 0..64 {
+                let s1 = _mm512_xor_si512(
+                    _mm512_xor_si512(_mm512_ror_epi32(e, 6), _mm512_ror_epi32(e, 11)),
+                    _mm512_ror_epi32(e, 25),
+                );
+                let ch = _mm512_xor_si512(_mm512_and_si512(e, f), _mm512_andnot_si512(e, g));
+                let temp1 = _mm512_add_epi32(
+                    _mm512_add_epi32(_mm512_add_epi32(h, s1), ch),
+                    _mm512_add_epi32(k[i], w[i]),
+                );
+                let s0 = _mm512_xor_si512(
+                    _mm512_xor_si512(_mm512_ror_epi32(a, 2), _mm512_ror_epi32(a, 13)),
+                    _mm512_ror_epi32(a, 22),
+                );
+                let maj = _mm512_xor_si512(
+                    _mm512_xor_si512(_mm512_and_si512(a, b), _mm512_and_si512(a, c)),
+                    _mm512_and_si512(b, c),
+                );
+                let temp2 = _mm512_add_epi32(s0, maj);
+
+                h = g;
+                g = f;
+                f = e;
+                e = _mm512_add_epi32(d, temp1);
+                d = c;
+                c = b;
+                b = a;
+                a = _mm512_add_epi32(temp1, temp2);
+            }
+
+            state[0] = _mm512_add_epi32(state[0], a);
+            state[1] = _mm512_add_epi32(state[1], b);
+            state[2] = _mm512_add_epi32(state[2], c);
+            state[3] = _mm512_add_epi32(state[3], d);
+            state[4] = _mm512_add_epi32(state[4], e);
+            state[5] = _mm512_add_epi32(state[5], f);
+            state[6] = _mm512_add_epi32(state[6], g);
+            state[7] = _mm512_add_epi32(state[7], h);
+        }
+
+        #[target_feature(enable = "avx512f")]
+        pub(super) unsafe fn hash_batch16(
+            base_state: [u32; 8],
+            tails: &[Vec<u8>],
+        ) -> Vec<[u8; 32]> {
+            const LANES: usize = 16;
+            debug_assert_eq!(tails.len(), LANES);
+
+            let mut state: [__m512i; 8] =
+                std::array::from_fn(|i| _mm512_set1_epi32(base_state[i] as i32));
+            let k: [__m512i; 64] = std::array::from_fn(|i| _mm512_set1_epi32(K[i] as i32));
+
+            let block_count = tails[0].len() / 64;
+            for block_idx in 0..block_count {
+                let mut w = [_mm512_setzero_si512(); 64];
+                for (word_idx, w_slot) in w.iter_mut().enumerate().take(16) {
+                    let mut lane = [0i32; LANES];
+                    for (l, tail) in tails.iter().enumerate() {
+                        let off = block_idx * 64 + word_idx * 4;
+                        lane[l] = u32::from_be_bytes(tail[off..off + 4].try_into().unwrap()) as i32;
+                    }
+                    *w_slot = _mm512_set_epi32(
+                        lane[15], lane[14], lane[13], lane[12], lane[11], lane[10], lane[9],
+                        lane[8], lane[7], lane[6], lane[5], lane[4], lane[3], lane[2], lane[1],
+                        lane[0],
+                    );
+                }
+                expand_message(&mut w);
+                compress(&mut state, &w, &k);
+            }
+
+            let mut lane_words = [[0i32; LANES]; 8];
+            for (word_idx, lane_out) in lane_words.iter_mut().enumerate() {
+                _mm512_storeu_si512(lane_out.as_mut_ptr() as *mut __m512i, state[word_idx]);
+            }
+
+            (0..LANES)
+                .map(|l| {
+                    let mut out = [0u8; 32];
+                    for (word_idx, lane_out) in lane_words.iter().enumerate() {
+                        out[word_idx * 4..word_idx * 4 + 4]
+                            .copy_from_slice(&(lane_out[l] as u32).to_be_bytes());
+                    }
+                    out
+                })
+                .collect()
+        }
+    }
+}
+
 // Helper for high-speed hex writing without String allocations
 #[inline(always)]
 fn write_hex_bytes(buf: &mut [u8], mut n: u128, len: usize) {
@@ -69,6 +623,236 @@ fn check_match(digest: &[u8], expected_hex_prefix: &[u8]) -> bool {
     true
 }
 
+// Helper for formatting a full digest for display
+fn hex_string(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for &b in bytes {
+        s.push_str(&format!("{:02x}", b));
+    }
+    s
+}
+
+fn parse_nbits(s: &str) -> Result<u32, String> {
+    u32::from_str_radix(s.trim_start_matches("0x"), 16)
+        .map_err(|e| format!("invalid nbits '{}': {}", s, e))
+}
+
+/// Build a big-endian `digest_len`-byte difficulty target requiring at
+/// least `zero_bits` leading zero bits: the bytes covered fully by
+/// `zero_bits` are zeroed, the byte straddling the boundary is masked, and
+/// the rest are left at their maximum value.
+fn zero_bits_target(zero_bits: u32, digest_len: usize) -> Vec<u8> {
+    let zero_bits = zero_bits.min((digest_len * 8) as u32);
+    let mut target = vec![0xffu8; digest_len];
+    let full_zero_bytes = (zero_bits / 8) as usize;
+    for b in target.iter_mut().take(full_zero_bytes) {
+        *b = 0;
+    }
+    let rem_bits = zero_bits % 8;
+    if rem_bits > 0 && full_zero_bytes < digest_len {
+        target[full_zero_bytes] = 0xff >> rem_bits;
+    }
+    target
+}
+
+/// Decode a Bitcoin-style compact "nbits" difficulty target (a high
+/// exponent byte `e` and a 3-byte mantissa `m`, decoding to
+/// `m * 256^(e-3)`) into a big-endian `digest_len`-byte target.
+fn nbits_target(nbits: u32, digest_len: usize) -> Vec<u8> {
+    let exponent = (nbits >> 24) as i32;
+    let mantissa = (nbits & 0x00ff_ffff).to_be_bytes();
+
+    let mut target = vec![0u8; digest_len];
+    let start = digest_len as i32 - exponent;
+    for (i, &b) in mantissa[1..].iter().enumerate() {
+        let pos = start + i as i32;
+        if pos >= 0 && (pos as usize) < digest_len {
+            target[pos as usize] = b;
+        }
+    }
+    target
+}
+
+// Helper for high-speed big-endian `digest <= target` comparison, short
+// circuiting on the first differing byte
+#[inline(always)]
+fn meets_target(digest: &[u8], target: &[u8]) -> bool {
+    for (&d, &t) in digest.iter().zip(target.iter()) {
+        if d != t {
+            return d < t;
+        }
+    }
+    true
+}
+
+/// Expected number of attempts to find a digest at or below `target`,
+/// i.e. `2^digest_bits / (target + 1)`.
+fn expected_attempts(target: &[u8]) -> f64 {
+    let mut target_value = 0f64;
+    for &b in target {
+        target_value = target_value * 256.0 + b as f64;
+    }
+    2f64.powi((target.len() * 8) as i32) / (target_value + 1.0)
+}
+
+// Helper mirroring check_match but anchored to the end of the hex string,
+// for the literal suffix prefilter below
+#[inline(always)]
+fn check_suffix(digest: &[u8], expected_hex_suffix: &[u8]) -> bool {
+    let offset = digest.len() * 2 - expected_hex_suffix.len();
+    for (i, &expected_byte) in expected_hex_suffix.iter().enumerate() {
+        let hex_idx = offset + i;
+        let shift = if hex_idx.is_multiple_of(2) { 4 } else { 0 };
+        let nibble = (digest[hex_idx / 2] >> shift) & 0xf;
+        let actual_hex_char = if nibble < 10 {
+            b'0' + nibble
+        } else {
+            b'a' + (nibble - 10)
+        };
+        if actual_hex_char != expected_byte {
+            return false;
+        }
+    }
+    true
+}
+
+/// A required literal run of characters at the very start (`^literal...`)
+/// and/or very end (`...literal$`) of a regex pattern, used to cheaply
+/// reject candidates before running the full regex engine.
+struct LiteralAnchors {
+    prefix: Option<Vec<u8>>,
+    suffix: Option<Vec<u8>>,
+}
+
+const REGEX_METACHARS: &str = ".*+?()[]{}|\\^$";
+
+/// Whether `rest` starts with a quantifier that allows zero repetitions
+/// (`?`, `*`, or a `{m,n}` repetition whose minimum is 0). If so, the
+/// character right before it in the pattern is optional, not a hard
+/// literal, and the scan in `extract_literal_anchors` must not fold it in.
+fn starts_with_zero_min_quantifier(rest: &str) -> bool {
+    match rest.chars().next() {
+        Some('?') | Some('*') => true,
+        Some('{') => rest.find('}').is_some_and(|close| {
+            let min = rest[1..close].split(',').next().unwrap_or("");
+            min.is_empty() || min == "0"
+        }),
+        _ => false,
+    }
+}
+
+/// `check_match`/`check_suffix` index a digest of `digest_len` bytes, i.e.
+/// `digest_len * 2` hex characters, so anchors longer than that can never
+/// be compared against one; truncate to what the full regex engine below
+/// will still verify for real, rather than handing them a length that
+/// runs past the digest.
+fn extract_literal_anchors(pattern: &str, digest_len: usize) -> LiteralAnchors {
+    let max_hex_len = digest_len * 2;
+
+    // An alternation (`^ab|cd$`) can make neither end's literal required,
+    // and inline flags such as `(?i)` change how a literal compares against
+    // the lowercase hex, so no anchors are extracted from such patterns.
+    if pattern.contains('|') || pattern.contains("(?") {
+        return LiteralAnchors {
+            prefix: None,
+            suffix: None,
+        };
+    }
+
+    let prefix = pattern.strip_prefix('^').and_then(|rest| {
+        // A trailing zero-minimum quantifier (`?`, `*`, `{0,n}`) doesn't
+        // just stop the scan (it's a metacharacter already), it also makes
+        // the character it applies to optional, so that character must not
+        // be folded into the literal either -- stop one character earlier
+        // in that case.
+        let mut end = 0;
+        for (i, c) in rest.char_indices() {
+            if REGEX_METACHARS.contains(c) {
+                break;
+            }
+            let after = i + c.len_utf8();
+            if starts_with_zero_min_quantifier(&rest[after..]) {
+                break;
+            }
+            end = after;
+        }
+        let literal: String = rest[..end].chars().take(max_hex_len).collect();
+        (!literal.is_empty()).then(|| literal.into_bytes())
+    });
+
+    let suffix = pattern.strip_suffix('$').and_then(|rest| {
+        let mut run: Vec<char> = rest
+            .chars()
+            .rev()
+            .take_while(|c| !REGEX_METACHARS.contains(*c))
+            .collect();
+        // `run` is reversed, so its last element is the run's first
+        // character; a backslash right before it makes that an escape
+        // (`\d$`), not a literal.
+        let run_len: usize = run.iter().map(|c| c.len_utf8()).sum();
+        if rest[..rest.len() - run_len].ends_with('\\') {
+            run.pop();
+        }
+        let literal: String = run
+            .into_iter()
+            .take(max_hex_len)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .collect();
+        (!literal.is_empty()).then(|| literal.into_bytes())
+    });
+
+    LiteralAnchors { prefix, suffix }
+}
+
+/// A compiled `--pattern` vanity search: the full regex plus any literal
+/// anchors pulled out of it for the cheap prefilter. Uses `fancy_regex`
+/// rather than `regex` so patterns can use backreferences (e.g. matching
+/// repeated hex digit runs), which `regex`'s non-backtracking engine
+/// can't express.
+struct PatternMatcher {
+    regex: fancy_regex::Regex,
+    anchors: LiteralAnchors,
+}
+
+impl PatternMatcher {
+    // `fancy_regex::Error` is much larger than `regex::Error` was, so box it
+    // to keep this `Result` small (clippy::result_large_err).
+    fn new(pattern: &str, digest_len: usize) -> Result<Self, Box<fancy_regex::Error>> {
+        Ok(PatternMatcher {
+            regex: fancy_regex::Regex::new(pattern)?,
+            anchors: extract_literal_anchors(pattern, digest_len),
+        })
+    }
+
+    fn matches(&self, digest: &[u8]) -> bool {
+        if let Some(prefix) = &self.anchors.prefix {
+            if !check_match(digest, prefix) {
+                return false;
+            }
+        }
+        if let Some(suffix) = &self.anchors.suffix {
+            if !check_suffix(digest, suffix) {
+                return false;
+            }
+        }
+        self.regex.is_match(&hex_string(digest)).unwrap_or(false)
+    }
+}
+
+/// The acceptance criterion `solve` searches for.
+enum MatchMode {
+    /// Classic fixed-point mode: the written hex digits equal the hash's
+    /// prefix.
+    FixedPoint,
+    /// Proof-of-work mode: the digest, as a big-endian integer, is at or
+    /// below a difficulty target.
+    Target(Vec<u8>),
+    /// Vanity mode: the full lowercase-hex digest matches a regex.
+    Pattern(PatternMatcher),
+}
+
 fn format_hash_rate(hashes_per_sec: f64) -> String {
     if hashes_per_sec >= 1_000_000_000.0 {
         format!("{:.2} GH/s", hashes_per_sec / 1_000_000_000.0)
@@ -95,7 +879,13 @@ fn format_time(seconds: u64) -> String {
     }
 }
 
-fn solve(length: u8, template: &str, verbosity: VerbosityLevel) {
+fn solve(
+    length: u8,
+    template: &str,
+    algorithm: Algorithm,
+    mode: MatchMode,
+    verbosity: VerbosityLevel,
+) {
     let hash_placeholder_idx = template.find('#').expect("Template must contain #");
 
     let prefix = &template[..hash_placeholder_idx];
@@ -105,10 +895,25 @@ fn solve(length: u8, template: &str, verbosity: VerbosityLevel) {
     template_bytes.extend_from_slice(&vec![b'0'; length as usize]);
     template_bytes.extend_from_slice(suffix.as_bytes());
 
+    let digest_len = algorithm.output_len();
     let max_count: u64 = 16u64.pow(length as u32);
     let start_time = Instant::now();
     let num_threads = rayon::current_num_threads();
 
+    // The midstate cache only applies to the default SHA-256 path; only
+    // whole blocks that lie entirely before the placeholder can be folded
+    // into it. If the placeholder falls inside the first block, or a
+    // different algorithm was chosen, there's nothing to save: the SIMD
+    // batch path below then starts from the initial SHA-256 state, and the
+    // per-candidate path falls back to the generic HashAlgo.
+    let midstate_block_count = hash_placeholder_idx / 64;
+    let midstate_bytes = midstate_block_count * 64;
+    let midstate = if algorithm == Algorithm::Sha256 && midstate_block_count > 0 {
+        Some(sha256::compute_midstate(&template_bytes[..midstate_bytes]))
+    } else {
+        None
+    };
+
     // Print initial information based on verbosity
     match verbosity {
         VerbosityLevel::Verbose => {
@@ -116,12 +921,36 @@ fn solve(length: u8, template: &str, verbosity: VerbosityLevel) {
                 "Template: {}",
                 template.replace('#', &"#".repeat(length as usize))
             );
-            println!("Digits to match: {}", length);
+            println!("Algorithm: {}", algorithm);
+            match &mode {
+                MatchMode::Target(target) => {
+                    println!("Mode: difficulty target");
+                    println!("Target: {}", hex_string(target));
+                    println!("Expected attempts: ~{:.0}", expected_attempts(target));
+                }
+                MatchMode::Pattern(_) => {
+                    println!("Mode: vanity pattern search");
+                }
+                MatchMode::FixedPoint => {
+                    println!("Digits to match: {}", length);
+                }
+            }
             println!("Search space: {} possible combinations", max_count);
             println!("Threads available: {}\n", num_threads);
         }
         VerbosityLevel::Normal => {
-            println!("Searching for {}-digit hash prefix match...", length);
+            match &mode {
+                MatchMode::Target(_) => {
+                    println!("Searching for a {} proof-of-work nonce...", algorithm);
+                }
+                MatchMode::Pattern(_) => {
+                    println!("Searching for a {} hash matching the pattern...", algorithm);
+                }
+                MatchMode::FixedPoint => println!(
+                    "Searching for {}-digit {} hash prefix match...",
+                    length, algorithm
+                ),
+            }
         }
         VerbosityLevel::Quiet => {}
     }
@@ -176,6 +1005,20 @@ fn solve(length: u8, template: &str, verbosity: VerbosityLevel) {
         None
     };
 
+    // On x86_64 with SHA-256 selected, pack several candidates into parallel
+    // SIMD lanes so one call to the compression function advances all of
+    // them at once. `lanes` is 1 (no batching) everywhere else.
+    #[cfg(target_arch = "x86_64")]
+    let simd_level = if algorithm == Algorithm::Sha256 {
+        simd_sha256::Level::detect()
+    } else {
+        simd_sha256::Level::Scalar
+    };
+    #[cfg(target_arch = "x86_64")]
+    let lanes = simd_level.lanes();
+    #[cfg(not(target_arch = "x86_64"))]
+    let lanes: usize = 1;
+
     // High-performance loop
     let chunk_size: u64 = 2048;
     let result = (0..(max_count / chunk_size + 1))
@@ -185,33 +1028,103 @@ fn solve(length: u8, template: &str, verbosity: VerbosityLevel) {
                 return None;
             }
 
-            let mut hasher = Sha256::new();
+            let mut hasher = make_hash_algo(algorithm);
             let mut local_buf = template_bytes.clone();
             let start = chunk_idx * chunk_size;
             let end = std::cmp::min(start + chunk_size, max_count);
+            let mut i = start;
+
+            #[cfg(target_arch = "x86_64")]
+            if lanes > 1 {
+                let base_state = midstate
+                    .as_ref()
+                    .map(|m| m.state)
+                    .unwrap_or(sha256::INIT_STATE);
+
+                while i + lanes as u64 <= end {
+                    let mut lane_bufs: Vec<Vec<u8>> = Vec::with_capacity(lanes);
+                    let mut tails: Vec<Vec<u8>> = Vec::with_capacity(lanes);
+                    for lane in 0..lanes as u64 {
+                        let mut buf = local_buf.clone();
+                        write_hex_bytes(
+                            &mut buf
+                                [hash_placeholder_idx..hash_placeholder_idx + length as usize],
+                            (i + lane) as u128,
+                            length as usize,
+                        );
+                        tails.push(sha256::pad_message(midstate_bytes, &buf[midstate_bytes..]));
+                        lane_bufs.push(buf);
+                    }
+
+                    let digests = simd_sha256::hash_batch(simd_level, base_state, &tails);
+
+                    for (lane, digest) in digests.iter().enumerate() {
+                        let buf = &lane_bufs[lane];
+                        let is_match = match &mode {
+                            MatchMode::Target(target) => {
+                                meets_target(&digest[..digest_len], target)
+                            }
+                            MatchMode::Pattern(matcher) => matcher.matches(&digest[..digest_len]),
+                            MatchMode::FixedPoint => check_match(
+                                &digest[..digest_len],
+                                &buf[hash_placeholder_idx
+                                    ..hash_placeholder_idx + length as usize],
+                            ),
+                        };
+
+                        if is_match {
+                            FOUND.store(true, Ordering::SeqCst);
+                            return Some((
+                                String::from_utf8_lossy(buf).into_owned(),
+                                hex_string(&digest[..digest_len]),
+                            ));
+                        }
+                    }
+
+                    OPS_COUNT.fetch_add(lanes as u64, Ordering::Relaxed);
+                    i += lanes as u64;
+                }
+            }
 
-            for i in start..end {
+            let remainder_start = i;
+            for i in remainder_start..end {
                 write_hex_bytes(
                     &mut local_buf[hash_placeholder_idx..hash_placeholder_idx + length as usize],
                     i as u128,
                     length as usize,
                 );
 
-                hasher.update(&local_buf);
-                let hash_result = hasher.finalize_reset();
+                let mut hash_result = [0u8; MAX_DIGEST_LEN];
+                if let Some(midstate) = &midstate {
+                    let digest = sha256::finalize_from_midstate(
+                        midstate,
+                        midstate_bytes,
+                        &local_buf[midstate_bytes..],
+                    );
+                    hash_result[..32].copy_from_slice(&digest);
+                } else {
+                    hasher.update(&local_buf);
+                    hasher.finalize_into_reset(&mut hash_result);
+                }
+
+                let is_match = match &mode {
+                    MatchMode::Target(target) => meets_target(&hash_result[..digest_len], target),
+                    MatchMode::Pattern(matcher) => matcher.matches(&hash_result[..digest_len]),
+                    MatchMode::FixedPoint => check_match(
+                        &hash_result[..digest_len],
+                        &local_buf[hash_placeholder_idx..hash_placeholder_idx + length as usize],
+                    ),
+                };
 
-                if check_match(
-                    &hash_result,
-                    &local_buf[hash_placeholder_idx..hash_placeholder_idx + length as usize],
-                ) {
+                if is_match {
                     FOUND.store(true, Ordering::SeqCst);
                     return Some((
                         String::from_utf8_lossy(&local_buf).into_owned(),
-                        format!("{:x}", hash_result),
+                        hex_string(&hash_result[..digest_len]),
                     ));
                 }
             }
-            OPS_COUNT.fetch_add(end - start, Ordering::Relaxed);
+            OPS_COUNT.fetch_add(end - remainder_start, Ordering::Relaxed);
             None
         });
 
@@ -244,6 +1157,21 @@ fn solve(length: u8, template: &str, verbosity: VerbosityLevel) {
                 println!("=== MATCH FOUND ===");
                 println!("Total time: {}", format_time(elapsed.as_secs()));
                 println!("Total hashes searched: {}", total_ops);
+                match &mode {
+                    MatchMode::Target(target) => {
+                        println!("Effective difficulty: ~{:.0}", expected_attempts(target));
+                    }
+                    MatchMode::Pattern(matcher) => {
+                        if let Some(caps) = matcher.regex.captures(&hash).ok().flatten() {
+                            for (i, group) in caps.iter().enumerate().skip(1) {
+                                if let Some(group) = group {
+                                    println!("Capture group {}: {}", i, group.as_str());
+                                }
+                            }
+                        }
+                    }
+                    MatchMode::FixedPoint => {}
+                }
                 println!("Output string: {}", msg);
                 println!("Full hash: {}", hash);
             } else {
@@ -259,15 +1187,41 @@ fn solve(length: u8, template: &str, verbosity: VerbosityLevel) {
 fn main() {
     let args = Args::parse();
 
+    // Default the template to name whichever --algorithm was actually
+    // selected, rather than always claiming SHA-256.
+    let text = args.text.clone().unwrap_or_else(|| {
+        format!(
+            "The {} hash of this sentence begins with #.",
+            args.algorithm.display_name()
+        )
+    });
+
     // Validate that template contains the placeholder
-    if !args.text.contains('#') {
+    if !text.contains('#') {
         eprintln!("Error: Template must contain '#' placeholder for the hash");
         std::process::exit(1);
     }
 
-    // Validate digits range
-    if args.digits == 0 || args.digits > 32 {
-        eprintln!("Error: Digits must be between 1 and 32");
+    // In fixed-point mode --digits is a hash-prefix length, so it's also
+    // bounded by the algorithm's own digest size; in proof-of-work/vanity
+    // mode it only sizes the nonce field, so that bound doesn't apply and
+    // it gets its own message. Either way it's capped so `solve`'s
+    // `16u64.pow(digits)` search space can't overflow.
+    let is_fixed_point = args.zero_bits.is_none() && args.nbits.is_none() && args.pattern.is_none();
+    if is_fixed_point {
+        let max_digits = ((args.algorithm.output_len() * 2) as u8).min(MAX_SAFE_DIGITS);
+        if args.digits == 0 || args.digits > max_digits {
+            eprintln!(
+                "Error: Digits must be between 1 and {} for {}",
+                max_digits, args.algorithm
+            );
+            std::process::exit(1);
+        }
+    } else if args.digits == 0 || args.digits > MAX_SAFE_DIGITS {
+        eprintln!(
+            "Error: --digits (nonce width) must be between 1 and {}",
+            MAX_SAFE_DIGITS
+        );
         std::process::exit(1);
     }
 
@@ -296,5 +1250,30 @@ fn main() {
         eprintln!();
     }
 
-    solve(args.digits, &args.text, verbosity);
+    let digest_len = args.algorithm.output_len();
+    let mode = if let Some(zero_bits) = args.zero_bits {
+        if zero_bits as usize > digest_len * 8 {
+            eprintln!(
+                "Error: --zero-bits must be at most {} for {}",
+                digest_len * 8,
+                args.algorithm
+            );
+            std::process::exit(1);
+        }
+        MatchMode::Target(zero_bits_target(zero_bits, digest_len))
+    } else if let Some(nbits) = args.nbits {
+        MatchMode::Target(nbits_target(nbits, digest_len))
+    } else if let Some(pattern) = &args.pattern {
+        match PatternMatcher::new(pattern, digest_len) {
+            Ok(matcher) => MatchMode::Pattern(matcher),
+            Err(e) => {
+                eprintln!("Error: invalid --pattern regex: {}", e);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        MatchMode::FixedPoint
+    };
+
+    solve(args.digits, &text, args.algorithm, mode, verbosity);
 }